@@ -0,0 +1,140 @@
+// src-tauri/src/backend.rs
+//! Supervises the bundled FastAPI backend as a Tauri sidecar: spawns it on
+//! startup, waits for it to answer health checks, and keeps the child handle
+//! around so it can be restarted or killed cleanly on exit.
+
+use std::sync::Mutex;
+use std::time::Duration;
+
+use serde::Serialize;
+use tauri::api::process::{Command, CommandChild, CommandEvent};
+use tauri::{AppHandle, Manager};
+
+const HEALTH_URL: &str = "http://127.0.0.1:8000/health";
+const MAX_BACKOFF_SECS: u64 = 30;
+
+/// Managed state holding the sidecar child process, if one is currently running.
+#[derive(Default)]
+pub struct BackendState(pub Mutex<Option<CommandChild>>);
+
+#[derive(Clone, Serialize)]
+#[serde(rename_all = "camelCase")]
+pub struct BackendStatus {
+    running: bool,
+    healthy: bool,
+}
+
+impl BackendStatus {
+    pub fn new(running: bool, healthy: bool) -> Self {
+        Self { running, healthy }
+    }
+}
+
+/// Spawns the `fattura-backend` sidecar binary and returns its child handle;
+/// the caller is responsible for storing it in `BackendState`.
+fn spawn_sidecar(app: &AppHandle) -> Result<CommandChild, String> {
+    let (mut rx, child) = Command::new_sidecar("fattura-backend")
+        .map_err(|e| format!("failed to create sidecar command: {e}"))?
+        .spawn()
+        .map_err(|e| format!("failed to spawn backend sidecar: {e}"))?;
+
+    let app_handle = app.clone();
+    let pid = child.pid();
+    tauri::async_runtime::spawn(async move {
+        while let Some(event) = rx.recv().await {
+            match event {
+                CommandEvent::Stdout(line) => println!("[backend] {line}"),
+                CommandEvent::Stderr(line) => eprintln!("[backend] {line}"),
+                CommandEvent::Error(err) => eprintln!("[backend] sidecar error: {err}"),
+                CommandEvent::Terminated(payload) => {
+                    eprintln!("[backend] sidecar terminated: {:?}", payload.code);
+                    // Clear the tracked child so `running` reflects reality —
+                    // otherwise a crashed backend still reads as running. Only
+                    // this child is cleared: after `shutdown` or a restart the
+                    // slot is empty or holds a newer child, which stays.
+                    let state = app_handle.state::<BackendState>();
+                    let mut tracked = state.0.lock().unwrap();
+                    if tracked.as_ref().map(|c| c.pid()) == Some(pid) {
+                        tracked.take();
+                        drop(tracked);
+                        let _ = app_handle.emit_all("backend-status", BackendStatus::new(false, false));
+                        crate::notifications::notify_simple(
+                            "FatturaAnalyzer",
+                            "The backend has stopped unexpectedly.",
+                        );
+                    }
+                }
+                _ => {}
+            }
+        }
+    });
+
+    Ok(child)
+}
+
+/// Polls `/health` with exponential backoff until the backend responds
+/// successfully, then emits `backend-ready` to the frontend.
+async fn wait_until_healthy(app: &AppHandle) {
+    let client = reqwest::Client::new();
+    let mut backoff = Duration::from_millis(250);
+
+    loop {
+        match client.get(HEALTH_URL).send().await {
+            Ok(response) if response.status().is_success() => {
+                let _ = app.emit_all("backend-ready", ());
+                return;
+            }
+            _ => {
+                tokio::time::sleep(backoff).await;
+                backoff = (backoff * 2).min(Duration::from_secs(MAX_BACKOFF_SECS));
+            }
+        }
+    }
+}
+
+/// Launches the backend sidecar and starts the health-check loop. Called from
+/// the `setup` hook and again by `restart_backend`.
+pub fn launch(app: &AppHandle, state: &BackendState) -> Result<(), String> {
+    let child = spawn_sidecar(app)?;
+    *state.0.lock().unwrap() = Some(child);
+
+    let app_handle = app.clone();
+    tauri::async_runtime::spawn(async move {
+        wait_until_healthy(&app_handle).await;
+    });
+
+    Ok(())
+}
+
+/// Kills the tracked sidecar child, if any. Safe to call more than once.
+pub fn shutdown(state: &BackendState) {
+    if let Some(child) = state.0.lock().unwrap().take() {
+        let _ = child.kill();
+    }
+}
+
+#[tauri::command]
+pub async fn restart_backend(
+    app: AppHandle,
+    state: tauri::State<'_, BackendState>,
+) -> Result<(), String> {
+    shutdown(&state);
+    launch(&app, &state)
+}
+
+#[tauri::command]
+pub async fn get_backend_status(state: tauri::State<'_, BackendState>) -> Result<BackendStatus, String> {
+    let running = state.0.lock().unwrap().is_some();
+    let healthy = if running {
+        reqwest::Client::new()
+            .get(HEALTH_URL)
+            .send()
+            .await
+            .map(|r| r.status().is_success())
+            .unwrap_or(false)
+    } else {
+        false
+    };
+
+    Ok(BackendStatus::new(running, healthy))
+}
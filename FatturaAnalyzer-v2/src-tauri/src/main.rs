@@ -7,6 +7,14 @@
 use tauri::Manager;
 use std::env;
 
+mod backend;
+mod notifications;
+mod status;
+mod tray;
+mod updater;
+
+use backend::BackendState;
+
 // Tauri commands
 #[tauri::command]
 async fn app_ready() -> Result<String, String> {
@@ -52,13 +60,6 @@ async fn open_external_url(url: String) -> Result<(), String> {
     Ok(())
 }
 
-#[tauri::command]
-async fn show_notification(title: String, body: String) -> Result<(), String> {
-    // Future: implement native notifications
-    println!("📢 Notification: {} - {}", title, body);
-    Ok(())
-}
-
 #[tauri::command]
 async fn get_system_info() -> Result<serde_json::Value, String> {
     let system_info = serde_json::json!({
@@ -78,20 +79,36 @@ fn main() {
     env_logger::init();
     
     tauri::Builder::default()
+        .manage(BackendState::default())
+        .system_tray(tray::build())
+        .on_system_tray_event(|app, event| tray::handle_event(app, event))
         .setup(|app| {
             println!("🚀 FatturaAnalyzer v2 starting...");
-            
+
             // Set app icon and title
             let window = app.get_window("main").unwrap();
             window.set_title("FatturaAnalyzer v2").unwrap();
-            
+
             // Development specific setup
             #[cfg(debug_assertions)]
             {
                 println!("🔧 Development mode enabled");
                 window.open_devtools();
             }
-            
+
+            // Launch the bundled FastAPI backend; setup does not wait for it.
+            // `backend-ready` is emitted once it answers health checks.
+            let handle = app.handle();
+            let backend_state = app.state::<BackendState>();
+            backend::launch(&handle, &backend_state)
+                .map_err(|e| format!("failed to start backend: {e}"))?;
+
+            // Check for a newer signed release in the background.
+            updater::check_on_startup(&handle);
+
+            // Push backend health to the frontend instead of making it poll.
+            status::start_watcher(handle);
+
             println!("✅ Tauri setup completed");
             Ok(())
         })
@@ -100,17 +117,20 @@ fn main() {
             test_backend_connection,
             get_app_info,
             open_external_url,
-            show_notification,
-            get_system_info
+            get_system_info,
+            backend::restart_backend,
+            backend::get_backend_status,
+            updater::check_for_update,
+            notifications::show_notification,
+            status::stream_import_rows
         ])
         .on_window_event(|event| match event.event() {
             tauri::WindowEvent::CloseRequested { api, .. } => {
-                println!("👋 App closing...");
+                println!("👋 Hiding to tray...");
+                // Keep the backend supervisor and any background sync alive;
+                // only the tray's Quit action actually tears the app down.
                 api.prevent_close();
-                
-                // Here you could show a confirmation dialog
-                // For now, just close the app
-                event.window().close().unwrap();
+                event.window().hide().unwrap();
             }
             _ => {}
         })
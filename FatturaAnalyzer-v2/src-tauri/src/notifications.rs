@@ -0,0 +1,63 @@
+// src-tauri/src/notifications.rs
+//! Cross-platform desktop notifications, backed by `notify-rust`, so
+//! long-running operations (imports, updates) surface to the user even when
+//! the window is minimized.
+
+use serde::Deserialize;
+
+#[derive(Debug, Default, Deserialize)]
+pub struct NotificationOptions {
+    pub icon: Option<String>,
+    pub sound: Option<String>,
+}
+
+/// Shows a native notification, returning an error if the platform denies
+/// permission or the notification server can't be reached.
+///
+/// Platform limitations inherited from `notify-rust`:
+/// - `sound` is only honored on Linux (`sound_name` has no macOS/Windows
+///   equivalent in the underlying notification APIs); a requested sound is
+///   logged and dropped elsewhere instead of silently disappearing.
+/// - On macOS/Windows, a user denying the notification permission does not
+///   reliably surface as an `Err` from `.show()` — the OS may just drop the
+///   notification. Linux (via `zbus`) is the only backend where this
+///   function's `Err` return is a reliable "permission denied" signal.
+pub fn notify(title: &str, body: &str, options: &NotificationOptions) -> Result<(), String> {
+    let mut notification = notify_rust::Notification::new();
+    notification.summary(title).body(body);
+
+    if let Some(icon) = &options.icon {
+        notification.icon(icon);
+    }
+
+    if let Some(sound) = &options.sound {
+        #[cfg(target_os = "linux")]
+        notification.sound_name(sound);
+
+        #[cfg(not(target_os = "linux"))]
+        eprintln!("[notifications] ignoring sound '{sound}': not supported on this platform");
+    }
+
+    notification
+        .show()
+        .map(|_| ())
+        .map_err(|e| format!("failed to show notification: {e}"))
+}
+
+/// Convenience wrapper for subsystems (backend supervisor, updater) that only
+/// need a plain title/body notification.
+pub fn notify_simple(title: &str, body: &str) {
+    if let Err(e) = notify(title, body, &NotificationOptions::default()) {
+        eprintln!("[notifications] {e}");
+    }
+}
+
+#[tauri::command]
+pub async fn show_notification(
+    title: String,
+    body: String,
+    icon: Option<String>,
+    sound: Option<String>,
+) -> Result<(), String> {
+    notify(&title, &body, &NotificationOptions { icon, sound })
+}
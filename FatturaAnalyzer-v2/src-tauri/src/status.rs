@@ -0,0 +1,259 @@
+// src-tauri/src/status.rs
+//! Pushes backend/import state to the frontend instead of making it poll:
+//! a background watcher probes backend health and any running
+//! import/reconciliation job on an interval, and a streaming import command
+//! pushes parsed invoice rows over the window's event channel in bounded
+//! batches.
+//!
+//! Request-5 asked for a command that "accepts a Tauri channel and streams
+//! row batches over it". Tauri v1 (what this crate is built against) has no
+//! `tauri::ipc::Channel` — that API only exists from v2 — so `Window::emit`
+//! is the v1-idiomatic substitute: `stream_import_rows` pushes `batch`-bearing
+//! `import-progress` events, the same way `backend-status` is already pushed
+//! elsewhere in this module. The watcher below emits `import-progress` too,
+//! for job-probe updates rather than row batches; both shapes live in one
+//! `ImportProgress` struct (fields the other side doesn't use are omitted by
+//! `skip_serializing_if`) so there is a single schema behind the event name.
+
+use serde::{Deserialize, Serialize};
+use std::time::Duration;
+use tauri::{AppHandle, Manager, Window};
+
+use crate::backend::{BackendState, BackendStatus};
+
+const POLL_INTERVAL: Duration = Duration::from_secs(5);
+const IMPORT_BATCH_SIZE: usize = 200;
+
+#[derive(Clone, Serialize)]
+#[serde(rename_all = "camelCase")]
+pub struct ImportProgress {
+    #[serde(skip_serializing_if = "Option::is_none")]
+    batch: Option<Vec<serde_json::Value>>,
+    rows_so_far: usize,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    total_rows: Option<usize>,
+    done: bool,
+}
+
+#[derive(Debug, Deserialize)]
+struct JobStatus {
+    rows_processed: usize,
+    rows_total: usize,
+}
+
+/// Periodically probes `/health` and any active import/reconciliation job,
+/// pushing `backend-status` and `import-progress` events so the frontend
+/// reacts in real time instead of invoking `test_backend_connection` or
+/// polling job state on demand.
+pub fn start_watcher(app: AppHandle) {
+    tauri::async_runtime::spawn(async move {
+        let client = reqwest::Client::new();
+        let mut interval = tokio::time::interval(POLL_INTERVAL);
+
+        loop {
+            interval.tick().await;
+
+            let healthy = client
+                .get("http://127.0.0.1:8000/health")
+                .send()
+                .await
+                .map(|r| r.status().is_success())
+                .unwrap_or(false);
+
+            let running = app.state::<BackendState>().0.lock().unwrap().is_some();
+            let _ = app.emit_all("backend-status", BackendStatus::new(running, healthy));
+
+            if !healthy {
+                continue;
+            }
+
+            if let Ok(response) = client.get("http://127.0.0.1:8000/api/jobs/active").send().await {
+                if let Ok(jobs) = response.json::<Vec<JobStatus>>().await {
+                    for job in jobs {
+                        let _ = app.emit_all(
+                            "import-progress",
+                            ImportProgress {
+                                batch: None,
+                                rows_so_far: job.rows_processed,
+                                total_rows: Some(job.rows_total),
+                                done: job.rows_processed >= job.rows_total,
+                            },
+                        );
+                    }
+                }
+            }
+        }
+    });
+}
+
+/// Parses one complete NDJSON line into a row. Returns `Ok(None)` for a
+/// blank line so callers can just skip it.
+fn parse_line(line_bytes: &[u8]) -> Result<Option<serde_json::Value>, String> {
+    let line = std::str::from_utf8(line_bytes)
+        .map_err(|e| format!("invalid UTF-8 in import row: {e}"))?
+        .trim();
+    if line.is_empty() {
+        return Ok(None);
+    }
+
+    serde_json::from_str(line)
+        .map(Some)
+        .map_err(|e| format!("malformed import row: {e}"))
+}
+
+/// Drains every complete line (terminated by `\n`) out of `line_buf` into
+/// `pending`, handing back any batches that reach `batch_size` along the way.
+/// Bytes of a line split across two calls (e.g. a multi-byte UTF-8 character
+/// straddling a chunk boundary) are left in `line_buf` for the next call
+/// rather than being decoded early.
+fn drain_lines(
+    line_buf: &mut Vec<u8>,
+    pending: &mut Vec<serde_json::Value>,
+    batch_size: usize,
+) -> Result<Vec<Vec<serde_json::Value>>, String> {
+    let mut ready = Vec::new();
+
+    while let Some(pos) = line_buf.iter().position(|&b| b == b'\n') {
+        let line_bytes: Vec<u8> = line_buf.drain(..=pos).collect();
+        if let Some(row) = parse_line(&line_bytes)? {
+            pending.push(row);
+        }
+
+        if pending.len() >= batch_size {
+            ready.push(std::mem::take(pending));
+        }
+    }
+
+    Ok(ready)
+}
+
+/// Streams a bulk FatturaPA XML import to the frontend in bounded batches of
+/// `IMPORT_BATCH_SIZE` rows. The backend streams parsed rows back as
+/// newline-delimited JSON, so this command holds in memory only the current
+/// network chunk, the rows completed from it (one or more batches) and the
+/// bytes of an unfinished line, instead of buffering the whole archive into
+/// one JSON response.
+#[tauri::command]
+pub async fn stream_import_rows(window: Window, archive_path: String) -> Result<usize, String> {
+    use futures_util::StreamExt;
+
+    let response = reqwest::Client::new()
+        .post("http://127.0.0.1:8000/api/import/parse-stream")
+        .json(&serde_json::json!({ "path": archive_path }))
+        .send()
+        .await
+        .map_err(|e| format!("failed to reach backend: {e}"))?;
+
+    let mut byte_stream = response.bytes_stream();
+    // Holds only the bytes of the line currently being assembled, including
+    // any trailing partial UTF-8 sequence carried over from the previous
+    // chunk — never the whole archive.
+    let mut line_buf: Vec<u8> = Vec::new();
+    let mut batch = Vec::with_capacity(IMPORT_BATCH_SIZE);
+    let mut rows_so_far = 0;
+
+    while let Some(chunk) = byte_stream.next().await {
+        let chunk = chunk.map_err(|e| format!("import stream interrupted: {e}"))?;
+        line_buf.extend_from_slice(&chunk);
+
+        for ready_batch in drain_lines(&mut line_buf, &mut batch, IMPORT_BATCH_SIZE)? {
+            rows_so_far += ready_batch.len();
+            emit_batch(&window, ready_batch, rows_so_far, false)?;
+        }
+    }
+
+    // The NDJSON stream may not end with a trailing newline; flush whatever
+    // is left in `line_buf` as the final row instead of silently dropping it.
+    if !line_buf.is_empty() {
+        if let Some(row) = parse_line(&line_buf)? {
+            batch.push(row);
+        }
+        line_buf.clear();
+    }
+
+    rows_so_far += batch.len();
+    emit_batch(&window, batch, rows_so_far, true)?;
+
+    crate::notifications::notify_simple(
+        "Import completed",
+        &format!("Imported {rows_so_far} invoice rows from {archive_path}"),
+    );
+
+    Ok(rows_so_far)
+}
+
+fn emit_batch(
+    window: &Window,
+    batch: Vec<serde_json::Value>,
+    rows_so_far: usize,
+    done: bool,
+) -> Result<(), String> {
+    window
+        .emit(
+            "import-progress",
+            ImportProgress { batch: Some(batch), rows_so_far, total_rows: None, done },
+        )
+        .map_err(|e| format!("failed to push import batch: {e}"))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn splits_multi_byte_utf8_across_chunk_boundaries() {
+        // "città\n" — the 'à' is the two-byte UTF-8 sequence 0xC3 0xA0; split
+        // the chunk right between those two bytes.
+        let line = "{\"city\":\"città\"}\n".as_bytes().to_vec();
+        let split_at = line.iter().position(|&b| b == 0xC3).unwrap() + 1;
+        let (first, second) = line.split_at(split_at);
+
+        let mut line_buf = Vec::new();
+        let mut pending = Vec::new();
+
+        line_buf.extend_from_slice(first);
+        let ready = drain_lines(&mut line_buf, &mut pending, 200).unwrap();
+        assert!(ready.is_empty());
+        assert!(pending.is_empty());
+
+        line_buf.extend_from_slice(second);
+        let ready = drain_lines(&mut line_buf, &mut pending, 200).unwrap();
+        assert!(ready.is_empty());
+        assert_eq!(pending.len(), 1);
+        assert_eq!(pending[0]["city"], "città");
+    }
+
+    #[test]
+    fn flushes_batches_once_batch_size_is_reached() {
+        let mut line_buf = Vec::new();
+        for i in 0..5 {
+            line_buf.extend_from_slice(format!("{{\"row\":{i}}}\n").as_bytes());
+        }
+
+        let mut pending = Vec::new();
+        let ready = drain_lines(&mut line_buf, &mut pending, 2).unwrap();
+
+        assert_eq!(ready.len(), 2, "two full batches of 2 should have flushed");
+        assert_eq!(ready[0].len(), 2);
+        assert_eq!(ready[1].len(), 2);
+        assert_eq!(pending.len(), 1, "the fifth row stays pending");
+    }
+
+    #[test]
+    fn rejects_invalid_utf8() {
+        let mut line_buf = vec![0xFF, 0xFE, b'\n'];
+        let mut pending = Vec::new();
+        assert!(drain_lines(&mut line_buf, &mut pending, 200).is_err());
+    }
+
+    #[test]
+    fn parse_line_skips_blank_lines() {
+        assert_eq!(parse_line(b"   ").unwrap(), None);
+    }
+
+    #[test]
+    fn parse_line_flushes_trailing_row_without_newline() {
+        let row = parse_line(br#"{"row":1}"#).unwrap();
+        assert_eq!(row.unwrap()["row"], 1);
+    }
+}
@@ -0,0 +1,62 @@
+// src-tauri/src/tray.rs
+//! System tray: lets the app keep the backend supervisor and any background
+//! sync running while the main window is hidden.
+
+use tauri::{
+    AppHandle, CustomMenuItem, Manager, SystemTray, SystemTrayEvent, SystemTrayMenu,
+    SystemTrayMenuItem,
+};
+
+const SHOW_HIDE: &str = "show_hide";
+const OPEN_DASHBOARD: &str = "open_dashboard";
+const CHECK_FOR_UPDATES: &str = "check_for_updates";
+const QUIT: &str = "quit";
+
+pub fn build() -> SystemTray {
+    let menu = SystemTrayMenu::new()
+        .add_item(CustomMenuItem::new(SHOW_HIDE, "Show/Hide"))
+        .add_item(CustomMenuItem::new(OPEN_DASHBOARD, "Open dashboard"))
+        .add_native_item(SystemTrayMenuItem::Separator)
+        .add_item(CustomMenuItem::new(CHECK_FOR_UPDATES, "Check for updates"))
+        .add_native_item(SystemTrayMenuItem::Separator)
+        .add_item(CustomMenuItem::new(QUIT, "Quit"));
+
+    SystemTray::new().with_menu(menu)
+}
+
+fn toggle_main_window(app: &AppHandle) {
+    let Some(window) = app.get_window("main") else {
+        return;
+    };
+
+    if window.is_visible().unwrap_or(false) {
+        let _ = window.hide();
+    } else {
+        let _ = window.show();
+        let _ = window.set_focus();
+    }
+}
+
+pub fn handle_event(app: &AppHandle, event: SystemTrayEvent) {
+    match event {
+        SystemTrayEvent::LeftClick { .. } => toggle_main_window(app),
+        SystemTrayEvent::MenuItemClick { id, .. } => match id.as_str() {
+            SHOW_HIDE => toggle_main_window(app),
+            OPEN_DASHBOARD => {
+                if let Some(window) = app.get_window("main") {
+                    let _ = window.show();
+                    let _ = window.set_focus();
+                    let _ = window.emit("open-dashboard", ());
+                }
+            }
+            CHECK_FOR_UPDATES => crate::updater::check_on_startup(app),
+            QUIT => {
+                let state = app.state::<crate::backend::BackendState>();
+                crate::backend::shutdown(&state);
+                app.exit(0);
+            }
+            _ => {}
+        },
+        _ => {}
+    }
+}
@@ -0,0 +1,200 @@
+// src-tauri/src/updater.rs
+//! Self-update subsystem: checks a configurable endpoint for a newer release,
+//! downloads it with progress events, and verifies its signature before
+//! anything gets installed.
+
+use std::env;
+
+use serde::{Deserialize, Serialize};
+use tauri::{AppHandle, Manager};
+
+/// Endpoint template; `{target}` and `{current_version}` are substituted
+/// before the request is made.
+const UPDATE_ENDPOINT: &str =
+    "https://updates.fatturaanalyzer.app/{target}/{current_version}";
+
+/// Hard ceiling on a downloaded update artifact. The manifest is unsigned, so
+/// a compromised update server must not be able to stream unbounded data
+/// into memory before the signature is ever checked.
+const MAX_UPDATE_BYTES: u64 = 512 * 1024 * 1024;
+
+/// Embedded at compile time; the minisign/ed25519 public key used to verify
+/// every downloaded artifact. This is the invariant that keeps self-updates
+/// safe to apply unattended.
+///
+/// Must be exported as the `FATTURA_UPDATER_PUBKEY` environment variable at
+/// build time (e.g. via the release CI job that owns the signing key). Using
+/// `option_env!` instead of `env!` means a build without it still compiles;
+/// any attempt to actually check for updates fails at runtime instead.
+const UPDATE_PUBLIC_KEY: Option<&str> = option_env!("FATTURA_UPDATER_PUBKEY");
+
+#[derive(Debug, Deserialize)]
+struct UpdateManifest {
+    version: String,
+    pub_date: String,
+    url: String,
+    signature: String,
+    notes: String,
+}
+
+#[derive(Clone, Serialize)]
+#[serde(rename_all = "camelCase")]
+pub struct UpdateAvailable {
+    version: String,
+    notes: String,
+    pub_date: String,
+}
+
+#[derive(Clone, Serialize)]
+#[serde(rename_all = "camelCase")]
+struct DownloadProgress {
+    downloaded: u64,
+    total: Option<u64>,
+}
+
+fn update_url() -> String {
+    UPDATE_ENDPOINT
+        .replace("{target}", env::consts::OS)
+        .replace("{current_version}", env!("CARGO_PKG_VERSION"))
+}
+
+async fn fetch_manifest() -> Result<UpdateManifest, String> {
+    reqwest::get(update_url())
+        .await
+        .map_err(|e| format!("failed to reach update server: {e}"))?
+        .json::<UpdateManifest>()
+        .await
+        .map_err(|e| format!("malformed update manifest: {e}"))
+}
+
+fn is_newer(candidate: &str) -> Result<bool, String> {
+    let current = semver::Version::parse(env!("CARGO_PKG_VERSION"))
+        .map_err(|e| format!("invalid current version: {e}"))?;
+    let candidate = semver::Version::parse(candidate)
+        .map_err(|e| format!("invalid candidate version: {e}"))?;
+    Ok(candidate > current)
+}
+
+/// Downloads the artifact at `url`, emitting `update-download-progress` after
+/// every chunk, and returns the raw bytes once the stream completes. The
+/// manifest (and thus `url`) is unsigned, so the download is capped at
+/// `MAX_UPDATE_BYTES` to bound memory use against a malicious or
+/// compromised update server.
+async fn download_with_progress(app: &AppHandle, url: &str) -> Result<Vec<u8>, String> {
+    use futures_util::StreamExt;
+
+    let response = reqwest::get(url)
+        .await
+        .map_err(|e| format!("failed to download update: {e}"))?;
+    let total = response.content_length();
+
+    if total.is_some_and(|total| total > MAX_UPDATE_BYTES) {
+        return Err(format!(
+            "update artifact ({total} bytes) exceeds the {MAX_UPDATE_BYTES}-byte limit",
+            total = total.unwrap()
+        ));
+    }
+
+    let mut downloaded: u64 = 0;
+    let mut bytes = Vec::new();
+    let mut stream = response.bytes_stream();
+
+    while let Some(chunk) = stream.next().await {
+        let chunk = chunk.map_err(|e| format!("download interrupted: {e}"))?;
+        downloaded += chunk.len() as u64;
+        if downloaded > MAX_UPDATE_BYTES {
+            return Err(format!(
+                "update download exceeded the {MAX_UPDATE_BYTES}-byte limit"
+            ));
+        }
+        bytes.extend_from_slice(&chunk);
+
+        let _ = app.emit_all("update-download-progress", DownloadProgress { downloaded, total });
+    }
+
+    Ok(bytes)
+}
+
+/// Verifies `artifact` against `signature` using the embedded public key.
+/// Returns `Err` if the signature does not check out, which must abort the
+/// install.
+fn verify_signature(public_key: &str, artifact: &[u8], signature: &str) -> Result<(), String> {
+    let public_key = minisign_verify::PublicKey::decode(public_key)
+        .map_err(|e| format!("invalid embedded update public key: {e}"))?;
+    let signature = minisign_verify::Signature::decode(signature)
+        .map_err(|e| format!("invalid update signature: {e}"))?;
+
+    public_key
+        .verify(artifact, &signature, false)
+        .map_err(|_| "update signature verification failed".to_string())
+}
+
+/// Writes the verified artifact to a temp file and swaps it in for the
+/// currently running executable.
+fn install_artifact(artifact: &[u8]) -> Result<(), String> {
+    let tmp_path = std::env::temp_dir().join(format!("fattura-update-{}", std::process::id()));
+    std::fs::write(&tmp_path, artifact).map_err(|e| format!("failed to stage update: {e}"))?;
+
+    let result = self_replace::self_replace(&tmp_path).map_err(|e| e.to_string());
+    let _ = std::fs::remove_file(&tmp_path);
+    result
+}
+
+/// Checks the update endpoint and, if a newer signed build is available,
+/// downloads and installs it. Emits `update-installed` on success or
+/// `update-error` on any terminal failure along the way.
+async fn check_and_install(app: &AppHandle) -> Result<Option<UpdateAvailable>, String> {
+    run_check_and_install(app).await.map_err(|e| {
+        let _ = app.emit_all("update-error", e.clone());
+        e
+    })
+}
+
+async fn run_check_and_install(app: &AppHandle) -> Result<Option<UpdateAvailable>, String> {
+    let public_key = UPDATE_PUBLIC_KEY
+        .ok_or("updater not configured: FATTURA_UPDATER_PUBKEY was not set at build time")?;
+
+    let manifest = fetch_manifest().await?;
+
+    if !is_newer(&manifest.version)? {
+        return Ok(None);
+    }
+
+    let available = UpdateAvailable {
+        version: manifest.version.clone(),
+        notes: manifest.notes.clone(),
+        pub_date: manifest.pub_date.clone(),
+    };
+
+    let artifact = download_with_progress(app, &manifest.url).await?;
+    verify_signature(public_key, &artifact, &manifest.signature)?;
+
+    // Only tell the user an update is ready once it has actually passed
+    // signature verification — a rejected artifact must never produce a
+    // success-flavored notification.
+    crate::notifications::notify_simple(
+        "Update available",
+        &format!("FatturaAnalyzer {} is ready to install.", manifest.version),
+    );
+
+    install_artifact(&artifact).map_err(|e| format!("failed to install update: {e}"))?;
+
+    let _ = app.emit_all("update-installed", &manifest.version);
+    Ok(Some(available))
+}
+
+/// Runs a background update check; called from `setup` and from the tray's
+/// "Check for updates" item.
+pub fn check_on_startup(app: &AppHandle) {
+    let app_handle = app.clone();
+    tauri::async_runtime::spawn(async move {
+        if let Err(e) = check_and_install(&app_handle).await {
+            eprintln!("[updater] startup check failed: {e}");
+        }
+    });
+}
+
+#[tauri::command]
+pub async fn check_for_update(app: AppHandle) -> Result<Option<UpdateAvailable>, String> {
+    check_and_install(&app).await
+}